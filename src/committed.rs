@@ -0,0 +1,108 @@
+use super::{target, ConsumedToken, Take, TakeOwned, Token};
+
+/// A completed modification that has landed in the slot but not been
+/// finalized yet. Returned by `Prepared::apply_checkpoint` instead of
+/// discarding the pre-modification `T`, so the caller can inspect the
+/// output and either `commit` or `rollback` the change.
+pub struct Committed<OuterT, T, O> {
+    inner: OuterT,
+    previous: T,
+    output: O,
+}
+
+impl<OuterT, T, O> Committed<OuterT, T, O> {
+    pub(crate) fn new(inner: OuterT, previous: T, output: O) -> Self {
+        Self {
+            inner,
+            previous,
+            output,
+        }
+    }
+
+    pub fn output(&self) -> &O {
+        &self.output
+    }
+}
+
+impl<'t, OuterT, T, O> Committed<OuterT, T, O>
+where
+    OuterT: Take<T, target::Type> + TakeOwned<Token<'t, T>, target::Token>,
+    T: 't,
+{
+    /// Restores the original value, discarding the modification and its
+    /// output.
+    pub fn rollback(mut self) -> Token<'t, T> {
+        let current: &mut T = self.inner.take_mut();
+        *current = self.previous;
+        // Safety:
+        //
+        // the slot has just been restored to its pre-modification
+        // value, so taking the token now is equivalent to never having
+        // modified it.
+        unsafe { self.inner.take_owned() }
+    }
+
+    /// Finalizes the modification and drops the rollback snapshot.
+    pub fn commit(self) -> (O, ConsumedToken<'t, T>) {
+        // Safety:
+        //
+        // the modification already landed in the slot back when
+        // `apply_checkpoint` ran; this only releases the token.
+        let t = unsafe { self.inner.take_owned() };
+        (self.output, ConsumedToken::from(t))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Prepared;
+
+    #[test]
+    fn commit_returns_output_and_keeps_mutation() {
+        let mut value = 1i32;
+        let token = Token::from(&mut value);
+        let prepared = Prepared::new(token, |v: &mut i32| -> Result<i32, ()> {
+            *v += 1;
+            Ok(*v)
+        });
+
+        let committed = prepared.apply_checkpoint().unwrap();
+        assert_eq!(*committed.output(), 2);
+
+        let (o, consumed) = committed.commit();
+        assert_eq!(o, 2);
+        drop(consumed);
+        assert_eq!(value, 2);
+    }
+
+    #[test]
+    fn rollback_restores_the_pre_modification_value() {
+        let mut value = 1i32;
+        let token = Token::from(&mut value);
+        let prepared = Prepared::new(token, |v: &mut i32| -> Result<i32, ()> {
+            *v += 1;
+            Ok(*v)
+        });
+
+        let committed = prepared.apply_checkpoint().unwrap();
+        let t = committed.rollback();
+        drop(t);
+        assert_eq!(value, 1);
+    }
+
+    #[test]
+    fn apply_checkpoint_err_leaves_original_untouched() {
+        let mut value = 1i32;
+        let token = Token::from(&mut value);
+        let prepared = Prepared::new(token, |v: &mut i32| -> Result<(), &'static str> {
+            *v += 1;
+            Err("failed")
+        });
+
+        let (e, t) = prepared.apply_checkpoint().err().unwrap();
+        assert_eq!(e, "failed");
+        drop(t);
+        assert_eq!(value, 1);
+    }
+}
@@ -0,0 +1,100 @@
+use std::ffi::c_void;
+
+/// A type that can be handed to non-Rust code as an opaque pointer and
+/// reclaimed later, modeled on the Linux kernel's `ForeignOwnable`.
+/// Lets a pending `Prepared`/`Chain` transaction be parked in an async
+/// runtime or a C host that drives the commit later: only `'static`
+/// transactions are eligible, since the `Token` inside must outlive
+/// the round trip.
+pub trait ForeignOwnable: Sized {
+    /// A read-only view into the value while it is still owned by the
+    /// foreign side, produced by `ForeignOwnable::borrow`.
+    type Borrowed<'a>
+    where
+        Self: 'a;
+
+    /// Boxes and leaks `self`, returning an opaque pointer the foreign
+    /// side is responsible for eventually passing back to
+    /// `ForeignOwnable::from_foreign`.
+    fn into_foreign(self) -> *const c_void;
+
+    /// Reclaims a value previously handed out by
+    /// `ForeignOwnable::into_foreign`.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have come from a matching `into_foreign` call, and
+    /// this must be called at most once per `into_foreign` call, before
+    /// the value borrowed via `ForeignOwnable::borrow` is dropped.
+    unsafe fn from_foreign(ptr: *const c_void) -> Self;
+
+    /// Inspects the pending transaction behind `ptr` without consuming
+    /// it.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must have come from a matching `into_foreign` call, and
+    /// must not have already been passed to `from_foreign`.
+    unsafe fn borrow<'a>(ptr: *const c_void) -> Self::Borrowed<'a>;
+}
+
+impl<T: 'static> ForeignOwnable for Box<T> {
+    type Borrowed<'a> = &'a T;
+
+    fn into_foreign(self) -> *const c_void {
+        Box::into_raw(self) as *const c_void
+    }
+
+    unsafe fn from_foreign(ptr: *const c_void) -> Self {
+        // Safety: guaranteed by the caller's contract, per the trait's
+        // safety section above.
+        unsafe { Box::from_raw(ptr as *mut T) }
+    }
+
+    unsafe fn borrow<'a>(ptr: *const c_void) -> Self::Borrowed<'a> {
+        // Safety: guaranteed by the caller's contract, per the trait's
+        // safety section above.
+        unsafe { &*(ptr as *const T) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_foreign_pointer() {
+        let boxed = Box::new(42i32);
+
+        let ptr = boxed.into_foreign();
+        let borrowed = unsafe { Box::<i32>::borrow(ptr) };
+        assert_eq!(*borrowed, 42);
+
+        let reclaimed = unsafe { Box::<i32>::from_foreign(ptr) };
+        assert_eq!(*reclaimed, 42);
+    }
+
+    #[test]
+    fn round_trips_a_prepared_transaction() {
+        use crate::{Apply, Prepared, Token};
+
+        type PreparedI32 = Prepared<Token<'static, i32>, i32, fn(&mut i32) -> Result<i32, ()>, ()>;
+
+        let leaked: &'static mut i32 = Box::leak(Box::new(1i32));
+        let token = Token::from(leaked);
+        let f: fn(&mut i32) -> Result<i32, ()> = |v| {
+            *v += 1;
+            Ok(*v)
+        };
+        let prepared: PreparedI32 = Prepared::new(token, f);
+
+        let ptr = Box::new(prepared).into_foreign();
+
+        let borrowed: &PreparedI32 = unsafe { Box::<PreparedI32>::borrow(ptr) };
+        let _ = borrowed;
+
+        let reclaimed: Box<PreparedI32> = unsafe { Box::<PreparedI32>::from_foreign(ptr) };
+        let (o, _consumed) = reclaimed.apply().unwrap();
+        assert_eq!(o, 2);
+    }
+}
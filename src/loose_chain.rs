@@ -0,0 +1,70 @@
+use super::Apply;
+
+/// Applies exactly two prepared modifications independently, instead
+/// of all-or-none like `Chain`. Each link commits into its own slot on
+/// success; a failing link does not roll back the other one. This is a
+/// two-link combinator, not an n-ary chain: nest a further
+/// modification with `Chain` (or call `apply_each` and handle it
+/// separately) if more than two links are needed.
+pub struct LooseChain<A1, A2> {
+    a1: A1,
+    a2: A2,
+}
+
+impl<A1, A2> LooseChain<A1, A2> {
+    pub fn new(a1: A1, a2: A2) -> Self {
+        Self { a1, a2 }
+    }
+
+    /// Applies both links independently, returning one outcome per
+    /// link: `Ok((output, token))` for a link that committed, or the
+    /// `(error, T)` pair `Apply::apply` would have returned for a link
+    /// that failed.
+    pub fn apply_each<'t1, 't2, T1, T2, F1, F2, O1, O2, E1, E2>(
+        self,
+    ) -> (
+        crate::AllOrNone<'t1, O1, E1, T1>,
+        crate::AllOrNone<'t2, O2, E2, T2>,
+    )
+    where
+        A1: Apply<'t1, T1, F1, O1, E1>,
+        A2: Apply<'t2, T2, F2, O2, E2>,
+    {
+        (self.a1.apply(), self.a2.apply())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Prepared, Token};
+
+    #[test]
+    fn applies_each_link_independently() {
+        let mut ok_value = 1i32;
+        let ok_token = Token::from(&mut ok_value);
+        let ok_prepared = Prepared::new(ok_token, |v: &mut i32| -> Result<i32, ()> {
+            *v += 1;
+            Ok(*v)
+        });
+
+        let mut err_value = 10i32;
+        let err_token = Token::from(&mut err_value);
+        let err_prepared = Prepared::new(err_token, |v: &mut i32| -> Result<(), &'static str> {
+            *v += 1;
+            Err("failed")
+        });
+
+        let (ok_outcome, err_outcome) = LooseChain::new(ok_prepared, err_prepared).apply_each();
+
+        let (o, consumed) = ok_outcome.unwrap();
+        assert_eq!(o, 2);
+        drop(consumed);
+        assert_eq!(ok_value, 2);
+
+        let (e, t) = err_outcome.unwrap_err();
+        assert_eq!(e, "failed");
+        drop(t);
+        assert_eq!(err_value, 10);
+    }
+}
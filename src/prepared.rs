@@ -1,4 +1,7 @@
-use super::{target, Apply, Chain, ConsumedToken, PartialApply, Take, TakeOwned, Token};
+use super::{
+    target, Apply, Chain, Committed, ConsumedToken, LooseChain, PartialApply, Take, TakeOwned,
+    Token,
+};
 use std::marker::PhantomData;
 
 /// Holds a single scoped modification into a copy of `T`.
@@ -54,6 +57,13 @@ where {
         Chain::new(self, a2)
     }
 
+    /// Pairs this Prepared modification with another one for independent
+    /// application: unlike `chain`, a failure in one does not roll back
+    /// the other.
+    pub fn loose_chain<A2>(self, a2: A2) -> LooseChain<Self, A2> {
+        LooseChain::new(self, a2)
+    }
+
     pub fn unchecked_cancel<'t>(self) -> Token<'t, T>
     where
         T: 't,
@@ -136,3 +146,36 @@ where
         Ok((o, consumed))
     }
 }
+
+impl<'t, OuterT, T, F, O, E> Prepared<OuterT, T, F, E>
+where
+    Self: PartialApply<T, F, O, E>,
+    OuterT: Take<Token<'t, T>, target::Token> + TakeOwned<Token<'t, T>, target::Token>,
+    T: 't + Clone,
+    E: 't,
+    F: 't + Clone,
+    OuterT: 't,
+{
+    /// Like `Apply::apply`, but keeps the pre-modification `T` around in
+    /// a `Committed` handle instead of discarding it, so the caller can
+    /// roll back after seeing the output.
+    pub fn apply_checkpoint(mut self) -> Result<Committed<OuterT, T, O>, (E, Token<'t, T>)> {
+        let previous = self.get_next();
+        let next = previous.clone();
+        let f = self.f.clone();
+
+        let (o, next) = match Self::modify_next(next, f) {
+            Ok(v) => v,
+            Err(e) => {
+                // Safety: same as `Apply::apply` - indicates the
+                // mutation failed and prevents further mutations.
+                let t = unsafe { self.inner.take_owned() };
+                return Err((e, t));
+            }
+        };
+        // Safety: only replace after the modification was successful.
+        self.replace(next);
+
+        Ok(Committed::new(self.inner, previous, o))
+    }
+}
@@ -0,0 +1,209 @@
+use super::{target, Apply, ConsumedToken, Take, TakeOwned, Token};
+use std::marker::PhantomData;
+use std::ptr;
+
+/// What to do with the slot if `f` unwinds during `PreparedInPlace::apply`.
+///
+/// There is no clone of `T` to fall back on here, so an unwinding `f`
+/// would otherwise leave the slot holding uninitialized memory.
+pub enum OnUnwind<T> {
+    /// Abort the process, mirroring `take_mut::take`'s default
+    /// behavior.
+    Abort,
+    /// Write this fallback value into the slot before the unwind
+    /// resumes, mirroring `replace_with`'s recovery closures.
+    Sentinel(T),
+}
+
+impl<T: Default> OnUnwind<T> {
+    /// Convenience constructor for `OnUnwind::Sentinel` using `T`'s
+    /// default value as the fallback.
+    pub fn sentinel_default() -> Self {
+        OnUnwind::Sentinel(T::default())
+    }
+}
+
+/// Holds a single scoped modification applied directly to the original
+/// `T`, without cloning it first.
+/// Unlike `Prepared`, an `Err(e)` from `f` still leaves the (possibly
+/// partially mutated) `T` in the slot, so only use this for
+/// modifications that don't depend on one another.
+pub struct PreparedInPlace<OuterT, T, F, E> {
+    inner: OuterT,
+    f: F,
+    on_unwind: OnUnwind<T>,
+    _t: PhantomData<T>,
+    _err: PhantomData<E>,
+}
+
+impl<OuterT, T, F, E> PreparedInPlace<OuterT, T, F, E> {
+    pub fn new(outer: OuterT, f: F, on_unwind: OnUnwind<T>) -> Self {
+        Self {
+            inner: outer,
+            f,
+            on_unwind,
+            _t: PhantomData,
+            _err: PhantomData,
+        }
+    }
+}
+
+/// Restores `*ptr` on drop unless `Guard::disarm` was called first.
+/// The `take_mut`/`replace_with` panic-guard technique: runs while the
+/// slot is logically empty, and either aborts or writes the sentinel
+/// back in on an unwind, depending on `on_unwind`.
+struct Guard<T> {
+    ptr: *mut T,
+    on_unwind: OnUnwind<T>,
+    disarmed: bool,
+}
+
+impl<T> Guard<T> {
+    fn disarm(&mut self) {
+        self.disarmed = true;
+    }
+}
+
+impl<T> Drop for Guard<T> {
+    fn drop(&mut self) {
+        if self.disarmed {
+            return;
+        }
+        match std::mem::replace(&mut self.on_unwind, OnUnwind::Abort) {
+            OnUnwind::Abort => std::process::abort(),
+            // Safety: the slot is still logically empty (its previous
+            // contents were `ptr::read` out before `f` ran and never
+            // written back), so this is the first write back into it.
+            OnUnwind::Sentinel(fallback) => unsafe { ptr::write(self.ptr, fallback) },
+        }
+    }
+}
+
+unsafe impl<'t, OuterT, T, F, O, E> Apply<'t, T, F, O, E> for PreparedInPlace<OuterT, T, F, E>
+where
+    OuterT: Take<T, target::Type> + TakeOwned<Token<'t, T>, target::Token>,
+    F: FnOnce(&mut T) -> Result<O, E>,
+    T: 't,
+    E: 't,
+    OuterT: 't,
+{
+    fn apply(mut self) -> crate::AllOrNone<'t, O, E, T> {
+        let slot: &mut T = self.inner.take_mut();
+        let ptr: *mut T = slot;
+        let f = self.f;
+
+        let mut guard = Guard {
+            ptr,
+            on_unwind: self.on_unwind,
+            disarmed: false,
+        };
+
+        // Safety: `ptr` is a live, exclusively-owned `&mut T` reborrowed
+        // as a raw pointer, so reading it out and writing a value back
+        // in (either here on a normal return, or in `Guard::drop` on
+        // unwind) is a valid move in and out of the slot.
+        let result = unsafe {
+            let mut value = ptr::read(ptr);
+            let outcome = f(&mut value);
+            ptr::write(ptr, value);
+            outcome
+        };
+        guard.disarm();
+
+        match result {
+            Ok(o) => {
+                // Safety:
+                //
+                // this is indicating that the mutation was successful,
+                // and also preventing further mutations
+                let t = unsafe { self.inner.take_owned() };
+                Ok((o, ConsumedToken::from(t)))
+            }
+            Err(e) => {
+                // Safety:
+                //
+                // this is indicating that the mutation failed, and also
+                // preventing further mutations. Note that `T` here may
+                // be partially mutated, unlike the cloning `Prepared`
+                // path.
+                let t = unsafe { self.inner.take_owned() };
+                Err((e, t))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::panic::{self, AssertUnwindSafe};
+
+    #[test]
+    fn ok_round_trip_mutates_in_place() {
+        let mut value = 1i32;
+        let token = Token::from(&mut value);
+        let prepared = PreparedInPlace::new(
+            token,
+            |v: &mut i32| -> Result<i32, ()> {
+                *v += 1;
+                Ok(*v)
+            },
+            OnUnwind::Abort,
+        );
+
+        let (o, consumed) = prepared.apply().unwrap();
+        assert_eq!(o, 2);
+        drop(consumed);
+        assert_eq!(value, 2);
+    }
+
+    #[test]
+    fn err_round_trip_leaves_mutation_in_place() {
+        let mut value = 1i32;
+        let token = Token::from(&mut value);
+        let prepared = PreparedInPlace::new(
+            token,
+            |v: &mut i32| -> Result<(), &'static str> {
+                *v += 1;
+                Err("failed")
+            },
+            OnUnwind::Abort,
+        );
+
+        let (e, t) = prepared.apply().unwrap_err();
+        assert_eq!(e, "failed");
+        drop(t);
+        assert_eq!(value, 2);
+    }
+
+    #[test]
+    fn panic_writes_sentinel_and_drops_original_exactly_once() {
+        thread_local! {
+            static DROPS: Cell<u32> = Cell::new(0);
+        }
+
+        struct Counted(u32);
+        impl Drop for Counted {
+            fn drop(&mut self) {
+                DROPS.with(|d| d.set(d.get() + 1));
+            }
+        }
+
+        let mut value = Counted(1);
+        let token = Token::from(&mut value);
+        let prepared = PreparedInPlace::new(
+            token,
+            |v: &mut Counted| -> Result<(), ()> {
+                v.0 = 99;
+                panic!("boom");
+            },
+            OnUnwind::Sentinel(Counted(0)),
+        );
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| prepared.apply()));
+        assert!(result.is_err());
+        assert_eq!(value.0, 0);
+        DROPS.with(|d| assert_eq!(d.get(), 1));
+    }
+}
@@ -0,0 +1,70 @@
+use super::Token;
+use std::cell::UnsafeCell;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+const UNTAKEN: u8 = 0;
+const TAKEN: u8 = 1;
+
+/// A `Sync` cell that hands out its `Token` at most once, enforced at
+/// runtime by an atomic flag rather than the caller discipline `unsafe
+/// TakeOwned::take_owned` requires.
+/// Lets a value live in a `static` and still be picked up exactly once
+/// to start a `Prepared`/`Chain` transaction.
+pub struct TokenCell<T> {
+    taken: AtomicU8,
+    value: UnsafeCell<T>,
+}
+
+// Safety: access to `value` is gated by the atomic `taken` flag, which
+// only ever lets one caller through `take` reach the inner `&mut T`.
+unsafe impl<T: Send> Sync for TokenCell<T> {}
+
+impl<T> TokenCell<T> {
+    pub const fn new(value: T) -> Self {
+        Self {
+            taken: AtomicU8::new(UNTAKEN),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    /// Returns the `Token` wrapping the inner value the first time
+    /// this is called, and `None` on every call after that.
+    pub fn take(&self) -> Option<Token<'_, T>> {
+        self.taken
+            .compare_exchange(UNTAKEN, TAKEN, Ordering::AcqRel, Ordering::Acquire)
+            .ok()
+            .map(|_| {
+                // Safety: the compare_exchange above succeeds for at
+                // most one caller, so it is safe to hand out a unique
+                // reference into `value`.
+                unsafe { Token::from(&mut *self.value.get()) }
+            })
+    }
+
+    /// Resets the single-take flag, allowing `TokenCell::take` to hand
+    /// out the token again. Requires `&mut self`, so this is only
+    /// reachable once no `Token` borrowed from this cell is still
+    /// outstanding.
+    pub fn heal(&mut self) {
+        *self.taken.get_mut() = UNTAKEN;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn takes_once_then_heals() {
+        let mut cell = TokenCell::new(1i32);
+
+        let first = cell.take();
+        assert!(first.is_some());
+        drop(first);
+
+        assert!(cell.take().is_none());
+
+        cell.heal();
+        assert!(cell.take().is_some());
+    }
+}